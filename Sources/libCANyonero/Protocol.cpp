@@ -47,6 +47,56 @@ static Arbitration from_vector(Bytes::const_iterator& it) {
     return a;
 }
 
+Arbitration Arbitration::functional11bit() {
+    return {
+        .request = 0x7DF,
+        .replyPattern = 0x7E8,
+        .replyMask = 0x7F8,
+        .requestExtension = 0x00,
+        .replyExtension = 0x00,
+    };
+}
+
+Arbitration Arbitration::functional29bit(const uint8_t testerAddress) {
+    return {
+        .request = 0x18DB3300 | uint32_t(testerAddress),
+        .replyPattern = 0x18DA0000 | uint32_t(testerAddress) << 8,
+        .replyMask = 0x1FFFFF00,
+        .requestExtension = 0x00,
+        .replyExtension = 0x00,
+    };
+}
+
+Arbitration Arbitration::normalFixed(const uint8_t sourceAddress, const uint8_t targetAddress) {
+    return {
+        .request = 0x18DA0000 | uint32_t(targetAddress) << 8 | sourceAddress,
+        .replyPattern = 0x18DA0000 | uint32_t(sourceAddress) << 8 | targetAddress,
+        .replyMask = 0x1FFFFFFF,
+        .requestExtension = 0x00,
+        .replyExtension = 0x00,
+    };
+}
+
+Arbitration Arbitration::mixed(const uint8_t sourceAddress, const uint8_t targetAddress, const uint8_t addressExtension) {
+    return {
+        .request = 0x18CE0000 | uint32_t(targetAddress) << 8 | sourceAddress,
+        .replyPattern = 0x18CE0000 | uint32_t(sourceAddress) << 8 | targetAddress,
+        .replyMask = 0x1FFFFFFF,
+        .requestExtension = addressExtension,
+        .replyExtension = addressExtension,
+    };
+}
+
+Arbitration Arbitration::extended(const uint32_t request, const uint32_t reply, const uint8_t sourceAddress, const uint8_t targetAddress) {
+    return {
+        .request = request,
+        .replyPattern = reply,
+        .replyMask = 0x1FFFFFFF,
+        .requestExtension = targetAddress,
+        .replyExtension = sourceAddress,
+    };
+}
+
 const Info PDU::information() const {
     assert(_type == PDUType::info);
     return Info::from_vector(_payload);
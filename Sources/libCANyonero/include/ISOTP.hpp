@@ -8,6 +8,8 @@
 #include "Helpers.hpp"
 
 #include <algorithm>
+#include <map>
+#include <optional>
 #include <string>
 #include <vector>
 
@@ -46,21 +48,21 @@ struct Frame {
     {
     }
 
-    /// Returns an FC.
-    static Frame flowControl(FlowStatus status, uint8_t blockSize, uint8_t separationTime, uint8_t width) {
+    /// Returns an FC. If `fill` is `std::nullopt`, the frame is not padded to `width`.
+    static Frame flowControl(FlowStatus status, uint8_t blockSize, uint8_t separationTime, uint8_t width, std::optional<uint8_t> fill = ISOTP::padding) {
         uint8_t pci = uint8_t(Type::flowControl) | uint8_t(status);
         std::vector<uint8_t> vector { pci, blockSize, separationTime };
-        vector.resize(width, ISOTP::padding);
+        if (fill) { vector.resize(width, *fill); }
         return Frame(vector);
     }
 
-    /// Returns an SF.
-    static Frame single(const Bytes& bytes, uint8_t width) {
+    /// Returns an SF. If `fill` is `std::nullopt`, the frame is not padded to `width`.
+    static Frame single(const Bytes& bytes, uint8_t width, std::optional<uint8_t> fill = ISOTP::padding) {
         assert(bytes.size() <= 7);
         uint8_t pci = uint8_t(Type::single) | uint8_t(bytes.size());
         auto vector = std::vector<uint8_t> { pci };
         vector.insert(vector.end(), bytes.begin(), bytes.end());
-        vector.resize(width, ISOTP::padding);
+        if (fill) { vector.resize(width, *fill); }
         return Frame(vector);
     }
 
@@ -73,15 +75,15 @@ struct Frame {
         return Frame(vector);
     }
 
-    /// Returns a CF.
-    static Frame consecutive(uint8_t sequenceNumber, const Bytes& bytes, uint8_t count, uint8_t width) {
+    /// Returns a CF. If `fill` is `std::nullopt`, the frame is not padded to `width`.
+    static Frame consecutive(uint8_t sequenceNumber, const Bytes& bytes, uint8_t count, uint8_t width, std::optional<uint8_t> fill = ISOTP::padding) {
         assert(sequenceNumber <= 0x0F);
         assert(count);
         assert(count <= width);
         uint8_t pci = uint8_t(Type::consecutive) | uint8_t(sequenceNumber);
         auto vector = std::vector<uint8_t> { pci };
         vector.insert(vector.end(), bytes.begin(), bytes.begin() + count);
-        vector.resize(width, ISOTP::padding);
+        if (fill) { vector.resize(width, *fill); }
         return Frame(vector);
     }
 
@@ -224,6 +226,7 @@ public:
     uint8_t blockSize;
     uint16_t rxSeparationTime;
     uint16_t txSeparationTime;
+    std::optional<uint8_t> framePadding;
 
     // State
     State state = State::idle;
@@ -237,15 +240,17 @@ public:
 
     /// Create a ``Transceiver`` with a default configuration.
     Transceiver()
-    :behavior(Behavior::defensive), width(8), blockSize(0), rxSeparationTime(0), txSeparationTime(0)
+    :behavior(Behavior::defensive), width(8), blockSize(0), rxSeparationTime(0), txSeparationTime(0), framePadding(ISOTP::padding)
     {
     }
 
     /// Create a new ``Transceiver`` with a custom configuration.
     /// The rxSeparationTime and txSeparationTime are in microseconds.
     /// NOTE: txSeparationTime is only considered, if it is larger than the one reported in the respective flow.
-    Transceiver(Behavior behavior, Mode mode, uint8_t blockSize = 0x00, uint16_t rxSeparationTime = 0x00, uint16_t txSeparationTime = 0x00)
-    :behavior(behavior), width(mode == Mode::standard ? 8 : 7), blockSize(blockSize), rxSeparationTime(rxSeparationTime), txSeparationTime(txSeparationTime)
+    /// The framePadding is used to fill outgoing frames up to the width. Pass `std::nullopt` to send unpadded frames;
+    /// in that case, unpadded incoming single and consecutive frames are accepted as well.
+    Transceiver(Behavior behavior, Mode mode, uint8_t blockSize = 0x00, uint16_t rxSeparationTime = 0x00, uint16_t txSeparationTime = 0x00, std::optional<uint8_t> framePadding = ISOTP::padding)
+    :behavior(behavior), width(mode == Mode::standard ? 8 : 7), blockSize(blockSize), rxSeparationTime(rxSeparationTime), txSeparationTime(txSeparationTime), framePadding(framePadding)
     {
     }
 
@@ -257,7 +262,7 @@ public:
 
         if (bytes.size() < width) {
             // Content small enough to fit in a single frame, send single frame and leave state machine in `.idle`.
-            auto frame = Frame::single(bytes, width);
+            auto frame = Frame::single(bytes, width, framePadding);
             return { .type = Action::Type::writeFrames, .frames = { 1, frame } };
         }
 
@@ -273,7 +278,10 @@ public:
     Action didReceiveFrame(const Bytes& bytes) {
         if (bytes.size() != width) {
             // Allow unpadded flow control which some ECUs are using (BMW 8HP TCU, I'm looking at you!)
-            if (!(bytes.size() == 3 && bytes[0] >= 0x30 && bytes[0] <= 0x32)) {
+            bool unpaddedFlowControl = bytes.size() == 3 && bytes[0] >= 0x30 && bytes[0] <= 0x32;
+            // Allow unpadded single and consecutive frames, if we're not padding ourselves.
+            bool unpaddedData = !framePadding && !bytes.empty() && bytes.size() < width && ((bytes[0] & 0xF0) == 0x00 || (bytes[0] & 0xF0) == 0x20);
+            if (!unpaddedFlowControl && !unpaddedData) {
                 return { Action::Type::protocolViolation, "Incoming frame does not match predefined width." };
             }
         }
@@ -331,7 +339,7 @@ private:
                 auto nextFrames = std::vector<Frame> {};
                 for (uint16_t i = 0; i < numberOfUnconfirmedFrames; ++i) {
                     auto nextChunkSize = std::min(width - 1, static_cast<int>(sendingPayload.size()));
-                    auto nextFrame = Frame::consecutive(sendingSequenceNumber, sendingPayload, nextChunkSize, width);
+                    auto nextFrame = Frame::consecutive(sendingSequenceNumber, sendingPayload, nextChunkSize, width, framePadding);
                     sendingPayload.erase(sendingPayload.begin(), sendingPayload.begin() + nextChunkSize);
                     nextFrames.insert(nextFrames.end(), nextFrame);
                     
@@ -392,7 +400,7 @@ private:
                 }
                 state = State::receiving;
                 receivingSequenceNumber = 0x01;
-                auto frame = Frame::flowControl(Frame::FlowStatus::clearToSend, blockSize, rxSeparationTime, width);
+                auto frame = Frame::flowControl(Frame::FlowStatus::clearToSend, blockSize, rxSeparationTime, width, framePadding);
                 return {
                     .type = Action::Type::writeFrames,
                     .frames = { 1, frame }
@@ -403,9 +411,10 @@ private:
                 if (state != State::receiving) { return { Action::Type::protocolViolation, "Did receive CONSECUTIVE while we're not receiving." }; }
 
                 if (frame.consecutiveSequenceNumber() != receivingSequenceNumber) { return { Action::Type::protocolViolation, "Did receive CONSECUTIVE with unexpected sequence number." }; }
+                auto length = std::min<uint16_t>(width - 1, receivingPendingCounter);
+                if (length > bytes.size() - 1) { return { Action::Type::protocolViolation, "Did receive CONSECUTIVE with payload shorter than expected." }; }
                 receivingSequenceNumber = (receivingSequenceNumber + 1) & 0x0F;
 
-                auto length = std::min<uint16_t>(width - 1, receivingPendingCounter);
                 receivingPayload.insert(receivingPayload.end(), bytes.begin() + 1, bytes.begin() + 1 + length);
                 receivingPendingCounter -= length;
                 if (receivingPendingCounter == 0) {
@@ -421,7 +430,7 @@ private:
                 if (receivingUnconfirmedFramesCounter > 0) { return { Action::Type::waitForMore }; }
 
                 receivingUnconfirmedFramesCounter = blockSize;
-                auto frame = Frame::flowControl(Frame::FlowStatus::clearToSend, blockSize, rxSeparationTime, width);
+                auto frame = Frame::flowControl(Frame::FlowStatus::clearToSend, blockSize, rxSeparationTime, width, framePadding);
                 return {
                     .type = Action::Type::writeFrames,
                     .frames = { 1, frame }
@@ -444,6 +453,36 @@ private:
         receivingUnconfirmedFramesCounter = 0;
     }
 };
+
+/// Dispatches incoming frames from multiple responders, e.g. after a functional request,
+/// to one ``Transceiver`` per reply ID, so that concurrent multi-frame replies are reassembled independently.
+///
+/// All transceivers are created lazily from a common configuration.
+/// NOTE: Any flow control frames returned for a reply ID need to be sent to the (physical) request ID of that responder.
+class Demultiplexer {
+public:
+    std::map<uint32_t, Transceiver> transceivers;
+
+    /// Create a ``Demultiplexer``. The parameters are the same as for ``Transceiver``.
+    Demultiplexer(Transceiver::Behavior behavior, Transceiver::Mode mode, uint8_t blockSize = 0x00, uint16_t rxSeparationTime = 0x00, uint16_t txSeparationTime = 0x00, std::optional<uint8_t> framePadding = ISOTP::padding)
+    :prototype(behavior, mode, blockSize, rxSeparationTime, txSeparationTime, framePadding)
+    {
+    }
+
+    /// Call this for any incoming frame received from `replyId`.
+    Transceiver::Action didReceiveFrame(uint32_t replyId, const Bytes& bytes) {
+        auto it = transceivers.try_emplace(replyId, prototype).first;
+        return it->second.didReceiveFrame(bytes);
+    }
+
+    /// Drop all per-responder state, e.g. before sending the next functional request.
+    void reset() {
+        transceivers.clear();
+    }
+
+private:
+    Transceiver prototype;
+};
 #pragma GCC diagnostic push
 
 }
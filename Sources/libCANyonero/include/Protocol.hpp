@@ -77,6 +77,22 @@ struct Arbitration {
 
     void to_vector(Bytes& payload) const;
     static Arbitration from_vector(const Bytes& payload);
+
+    /// Returns the 11-bit functional (broadcast) arbitration, i.e. requests to `0x7DF`, accepting replies from `0x7E8` - `0x7EF`.
+    static Arbitration functional11bit();
+    /// Returns the 29-bit functional (broadcast) arbitration, i.e. requests to `0x18DB33xx`, accepting replies from `0x18DAxxyy`
+    /// where `xx` is the tester address and `yy` is any ECU address.
+    static Arbitration functional29bit(const uint8_t testerAddress = 0xF1);
+    /// Returns the 29-bit normal fixed (ISO 15765-4) arbitration for physical requests, i.e. requests to `0x18DAttss`,
+    /// accepting replies from `0x18DAsstt` where `ss` is the source (tester) address and `tt` is the target (ECU) address.
+    static Arbitration normalFixed(const uint8_t sourceAddress, const uint8_t targetAddress);
+    /// Returns the 29-bit mixed addressing arbitration for physical requests, i.e. requests to `0x18CEttss`,
+    /// accepting replies from `0x18CEsstt`. The address extension is sent and expected as the first data byte.
+    static Arbitration mixed(const uint8_t sourceAddress, const uint8_t targetAddress, const uint8_t addressExtension);
+    /// Returns the extended addressing arbitration, i.e. requests to `request` carrying `targetAddress` as the first data byte,
+    /// accepting replies from `reply` carrying `sourceAddress` as the first data byte.
+    /// NOTE: The reply mask is always `0x1FFFFFFF` (exact match), which also works for 11-bit identifiers.
+    static Arbitration extended(const uint32_t request, const uint32_t reply, const uint8_t sourceAddress, const uint8_t targetAddress);
 };
 
 /// The Channel protocol type.
@@ -0,0 +1,102 @@
+///
+/// CANyonero. (C) 2022 - 2023 Dr. Michael 'Mickey' Lauer <mickey@vanille-media.de>
+///
+#import <XCTest/XCTest.h>
+#import <Foundation/Foundation.h>
+
+#import <vector>
+#import <iostream>
+
+#import "Protocol.hpp"
+
+using namespace CANyonero;
+
+@interface Arbitration_Tests : XCTestCase
+
+@end
+
+@implementation Arbitration_Tests
+
+-(void)testFunctional11bit {
+    auto arbitration = Arbitration::functional11bit();
+    XCTAssertEqual(arbitration.request, 0x7DF);
+    XCTAssertEqual(arbitration.replyPattern, 0x7E8);
+    XCTAssertEqual(arbitration.replyMask, 0x7F8);
+    XCTAssertEqual(arbitration.requestExtension, 0x00);
+    XCTAssertEqual(arbitration.replyExtension, 0x00);
+
+    auto payload = Bytes();
+    arbitration.to_vector(payload);
+    auto expected = Bytes { 0x00, 0x00, 0x07, 0xDF, 0x00, 0x00, 0x00, 0x07, 0xE8, 0x00, 0x00, 0x07, 0xF8, 0x00 };
+    XCTAssertEqual(payload, expected);
+}
+
+-(void)testFunctional29bit {
+    auto arbitration = Arbitration::functional29bit();
+    XCTAssertEqual(arbitration.request, 0x18DB33F1);
+    XCTAssertEqual(arbitration.replyPattern, 0x18DAF100);
+    XCTAssertEqual(arbitration.replyMask, 0x1FFFFF00);
+    XCTAssertEqual(arbitration.requestExtension, 0x00);
+    XCTAssertEqual(arbitration.replyExtension, 0x00);
+
+    auto payload = Bytes();
+    arbitration.to_vector(payload);
+    auto expected = Bytes { 0x18, 0xDB, 0x33, 0xF1, 0x00, 0x18, 0xDA, 0xF1, 0x00, 0x1F, 0xFF, 0xFF, 0x00, 0x00 };
+    XCTAssertEqual(payload, expected);
+}
+
+-(void)testFunctional29bitCustomTester {
+    auto arbitration = Arbitration::functional29bit(0xF9);
+    XCTAssertEqual(arbitration.request, 0x18DB33F9);
+    XCTAssertEqual(arbitration.replyPattern, 0x18DAF900);
+    XCTAssertEqual(arbitration.replyMask, 0x1FFFFF00);
+
+    auto payload = Bytes();
+    arbitration.to_vector(payload);
+    auto expected = Bytes { 0x18, 0xDB, 0x33, 0xF9, 0x00, 0x18, 0xDA, 0xF9, 0x00, 0x1F, 0xFF, 0xFF, 0x00, 0x00 };
+    XCTAssertEqual(payload, expected);
+}
+
+-(void)testNormalFixed {
+    auto arbitration = Arbitration::normalFixed(0xF1, 0x10);
+    XCTAssertEqual(arbitration.request, 0x18DA10F1);
+    XCTAssertEqual(arbitration.replyPattern, 0x18DAF110);
+    XCTAssertEqual(arbitration.replyMask, 0x1FFFFFFF);
+    XCTAssertEqual(arbitration.requestExtension, 0x00);
+    XCTAssertEqual(arbitration.replyExtension, 0x00);
+
+    auto payload = Bytes();
+    arbitration.to_vector(payload);
+    auto expected = Bytes { 0x18, 0xDA, 0x10, 0xF1, 0x00, 0x18, 0xDA, 0xF1, 0x10, 0x1F, 0xFF, 0xFF, 0xFF, 0x00 };
+    XCTAssertEqual(payload, expected);
+}
+
+-(void)testMixed {
+    auto arbitration = Arbitration::mixed(0xF1, 0x10, 0x42);
+    XCTAssertEqual(arbitration.request, 0x18CE10F1);
+    XCTAssertEqual(arbitration.replyPattern, 0x18CEF110);
+    XCTAssertEqual(arbitration.replyMask, 0x1FFFFFFF);
+    XCTAssertEqual(arbitration.requestExtension, 0x42);
+    XCTAssertEqual(arbitration.replyExtension, 0x42);
+
+    auto payload = Bytes();
+    arbitration.to_vector(payload);
+    auto expected = Bytes { 0x18, 0xCE, 0x10, 0xF1, 0x42, 0x18, 0xCE, 0xF1, 0x10, 0x1F, 0xFF, 0xFF, 0xFF, 0x42 };
+    XCTAssertEqual(payload, expected);
+}
+
+-(void)testExtended {
+    auto arbitration = Arbitration::extended(0x6F1, 0x610, 0xF1, 0x10);
+    XCTAssertEqual(arbitration.request, 0x6F1);
+    XCTAssertEqual(arbitration.replyPattern, 0x610);
+    XCTAssertEqual(arbitration.replyMask, 0x1FFFFFFF);
+    XCTAssertEqual(arbitration.requestExtension, 0x10);
+    XCTAssertEqual(arbitration.replyExtension, 0xF1);
+
+    auto payload = Bytes();
+    arbitration.to_vector(payload);
+    auto expected = Bytes { 0x00, 0x00, 0x06, 0xF1, 0x10, 0x00, 0x00, 0x06, 0x10, 0x1F, 0xFF, 0xFF, 0xFF, 0xF1 };
+    XCTAssertEqual(payload, expected);
+}
+
+@end
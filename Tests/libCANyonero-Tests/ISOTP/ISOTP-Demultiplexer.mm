@@ -0,0 +1,90 @@
+///
+/// CANyonero. (C) 2022 - 2023 Dr. Michael 'Mickey' Lauer <mickey@vanille-media.de>
+///
+#import <XCTest/XCTest.h>
+#import <Foundation/Foundation.h>
+
+#import <algorithm>
+#import <numeric>
+#import <vector>
+#import <iostream>
+
+#import "ISOTP.hpp"
+
+using namespace CANyonero::ISOTP;
+
+@interface ISOTP_Demultiplexer : XCTestCase
+
+@property(nonatomic,assign,readonly) Demultiplexer* demux;
+
+@end
+
+@implementation ISOTP_Demultiplexer
+
+-(void)setUp {
+    _demux = new Demultiplexer(Transceiver::Behavior::strict, Transceiver::Mode::standard, 0, 0, 0);
+}
+
+-(void)tearDown {
+    delete _demux;
+}
+
+-(void)testInterleavedMultiFrameReplies {
+    auto first1 = std::vector<uint8_t> { 0x10, 0x09, 0x01, 0x02, 0x03, 0x04, 0x05, 0x06 };
+    auto action1 = _demux->didReceiveFrame(0x7E8, first1);
+    XCTAssertEqual(action1.type, Transceiver::Action::Type::writeFrames);
+    XCTAssertEqual(action1.frames.size(), 1);
+    XCTAssertEqual(action1.frames[0].type(), Frame::Type::flowControl);
+
+    auto first2 = std::vector<uint8_t> { 0x10, 0x08, 0x11, 0x12, 0x13, 0x14, 0x15, 0x16 };
+    auto action2 = _demux->didReceiveFrame(0x7E9, first2);
+    XCTAssertEqual(action2.type, Transceiver::Action::Type::writeFrames);
+    XCTAssertEqual(action2.frames.size(), 1);
+    XCTAssertEqual(action2.frames[0].type(), Frame::Type::flowControl);
+
+    auto consecutive2 = std::vector<uint8_t> { 0x21, 0x17, 0x18, padding, padding, padding, padding, padding };
+    auto action3 = _demux->didReceiveFrame(0x7E9, consecutive2);
+    XCTAssertEqual(action3.type, Transceiver::Action::Type::process);
+    auto expected2 = std::vector<uint8_t> { 0x11, 0x12, 0x13, 0x14, 0x15, 0x16, 0x17, 0x18 };
+    XCTAssertEqual(action3.data, expected2);
+
+    auto consecutive1 = std::vector<uint8_t> { 0x21, 0x07, 0x08, 0x09, padding, padding, padding, padding };
+    auto action4 = _demux->didReceiveFrame(0x7E8, consecutive1);
+    XCTAssertEqual(action4.type, Transceiver::Action::Type::process);
+    auto expected1 = std::vector<uint8_t> { 0x01, 0x02, 0x03, 0x04, 0x05, 0x06, 0x07, 0x08, 0x09 };
+    XCTAssertEqual(action4.data, expected1);
+
+    XCTAssertEqual(_demux->transceivers.size(), 2);
+}
+
+-(void)testSingleRepliesFromMultipleResponders {
+    auto single1 = std::vector<uint8_t> { 0x02, 0x7E, 0x00, padding, padding, padding, padding, padding };
+    auto action1 = _demux->didReceiveFrame(0x7E8, single1);
+    XCTAssertEqual(action1.type, Transceiver::Action::Type::process);
+    auto expected = std::vector<uint8_t> { 0x7E, 0x00 };
+    XCTAssertEqual(action1.data, expected);
+
+    auto action2 = _demux->didReceiveFrame(0x7EA, single1);
+    XCTAssertEqual(action2.type, Transceiver::Action::Type::process);
+    XCTAssertEqual(action2.data, expected);
+}
+
+-(void)testConsecutiveFromOtherResponderIsRejected {
+    auto first = std::vector<uint8_t> { 0x10, 0x09, 0x01, 0x02, 0x03, 0x04, 0x05, 0x06 };
+    auto action1 = _demux->didReceiveFrame(0x7E8, first);
+    XCTAssertEqual(action1.type, Transceiver::Action::Type::writeFrames);
+
+    auto consecutive = std::vector<uint8_t> { 0x21, 0x07, 0x08, 0x09, padding, padding, padding, padding };
+    auto action2 = _demux->didReceiveFrame(0x7E9, consecutive);
+    XCTAssertEqual(action2.type, Transceiver::Action::Type::protocolViolation);
+}
+
+-(void)testReset {
+    auto first = std::vector<uint8_t> { 0x10, 0x09, 0x01, 0x02, 0x03, 0x04, 0x05, 0x06 };
+    _demux->didReceiveFrame(0x7E8, first);
+    XCTAssertEqual(_demux->transceivers.size(), 1);
+    _demux->reset();
+    XCTAssertTrue(_demux->transceivers.empty());
+}
+
+@end
@@ -0,0 +1,83 @@
+///
+/// CANyonero. (C) 2022 - 2023 Dr. Michael 'Mickey' Lauer <mickey@vanille-media.de>
+///
+#import <XCTest/XCTest.h>
+#import <Foundation/Foundation.h>
+
+#import <algorithm>
+#import <numeric>
+#import <vector>
+#import <iostream>
+
+#import "ISOTP.hpp"
+
+using namespace CANyonero::ISOTP;
+
+@interface ISOTP_Receive_Unpadded : XCTestCase
+
+@property(nonatomic,assign,readonly) Transceiver* isotp;
+
+@end
+
+@implementation ISOTP_Receive_Unpadded
+
+-(void)setUp {
+    _isotp = new Transceiver(Transceiver::Behavior::strict, Transceiver::Mode::standard, 0, 0, 0, std::nullopt);
+}
+
+-(void)tearDown {
+    delete _isotp;
+}
+
+-(void)testSingleUnpadded {
+    auto single = std::vector<uint8_t>{ 0x02, 0x7E, 0x00 };
+    auto action = _isotp->didReceiveFrame(single);
+    XCTAssertEqual(action.type, Transceiver::Action::Type::process);
+    auto expected = std::vector<uint8_t> { 0x7E, 0x00 };
+    XCTAssertEqual(action.data, expected);
+}
+
+-(void)testSingleUnpaddedLengthExceedingPayload {
+    auto single = std::vector<uint8_t>{ 0x03, 0x7E, 0x00 };
+    auto action = _isotp->didReceiveFrame(single);
+    XCTAssertEqual(action.type, Transceiver::Action::Type::protocolViolation);
+}
+
+-(void)testFirstConsecutiveUnpadded {
+    auto first = std::vector<uint8_t> { 0x10, 0x09, 0x31, 0x32, 0x33, 0x34, 0x35, 0x36 };
+    auto firstAction = _isotp->didReceiveFrame(first);
+    XCTAssertEqual(firstAction.type, Transceiver::Action::Type::writeFrames);
+    XCTAssertEqual(firstAction.frames.size(), 1);
+    auto expected = std::vector<uint8_t> { 0x30, 0x00, 0x00 };
+    XCTAssertEqual(firstAction.frames[0].bytes, expected);
+
+    auto consecutive = std::vector<uint8_t> { 0x21, 0x37, 0x38, 0x39 };
+    auto secondAction = _isotp->didReceiveFrame(consecutive);
+    XCTAssertEqual(secondAction.type, Transceiver::Action::Type::process);
+    auto pdu = std::vector<uint8_t> { 0x31, 0x32, 0x33, 0x34, 0x35, 0x36, 0x37, 0x38, 0x39 };
+    XCTAssertEqual(secondAction.data, pdu);
+}
+
+-(void)testConsecutiveShorterThanPending {
+    auto first = std::vector<uint8_t> { 0x10, 0x09, 0x31, 0x32, 0x33, 0x34, 0x35, 0x36 };
+    _isotp->didReceiveFrame(first);
+
+    auto consecutive = std::vector<uint8_t> { 0x21, 0x37, 0x38 };
+    auto action = _isotp->didReceiveFrame(consecutive);
+    XCTAssertEqual(action.type, Transceiver::Action::Type::protocolViolation);
+}
+
+-(void)testFirstUnpaddedIsRejected {
+    auto first = std::vector<uint8_t> { 0x10, 0x09, 0x31, 0x32, 0x33, 0x34, 0x35 };
+    auto action = _isotp->didReceiveFrame(first);
+    XCTAssertEqual(action.type, Transceiver::Action::Type::protocolViolation);
+}
+
+-(void)testSingleUnpaddedIsRejectedWhenPadding {
+    auto isotp = Transceiver(Transceiver::Behavior::strict, Transceiver::Mode::standard, 0, 0, 0);
+    auto single = std::vector<uint8_t>{ 0x02, 0x7E, 0x00 };
+    auto action = isotp.didReceiveFrame(single);
+    XCTAssertEqual(action.type, Transceiver::Action::Type::protocolViolation);
+}
+
+@end
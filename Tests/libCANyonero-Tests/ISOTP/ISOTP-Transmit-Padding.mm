@@ -0,0 +1,111 @@
+///
+/// CANyonero. (C) 2022 - 2023 Dr. Michael 'Mickey' Lauer <mickey@vanille-media.de>
+///
+#import <XCTest/XCTest.h>
+#import <Foundation/Foundation.h>
+
+#import <algorithm>
+#import <numeric>
+#import <vector>
+#import <iostream>
+
+#import "ISOTP.hpp"
+
+using namespace CANyonero::ISOTP;
+
+@interface ISOTP_Transmit_Padding : XCTestCase
+
+@end
+
+@implementation ISOTP_Transmit_Padding
+
+-(void)testSingleCustomPadding {
+    auto isotp = Transceiver(Transceiver::Behavior::strict, Transceiver::Mode::standard, 0, 0, 0, 0x55);
+    auto message = std::vector<uint8_t> { 0x3E, 0x00 };
+    auto action = isotp.writePDU(message);
+    XCTAssertEqual(action.type, Transceiver::Action::Type::writeFrames);
+    XCTAssertEqual(action.frames.size(), 1);
+    auto frame = action.frames[0];
+    XCTAssertEqual(frame.bytes.size(), 8);
+    auto expected = std::vector<uint8_t>{ 0x02, 0x3E, 0x00, 0x55, 0x55, 0x55, 0x55, 0x55 };
+    XCTAssertEqual(frame.bytes, expected);
+}
+
+-(void)testConsecutiveCustomPadding {
+    auto isotp = Transceiver(Transceiver::Behavior::strict, Transceiver::Mode::standard, 0, 0, 0, 0x55);
+    auto message = std::vector<uint8_t> { 0x31, 0x32, 0x33, 0x34, 0x35, 0x36, 0x37, 0x38 };
+    auto firstAction = isotp.writePDU(message);
+    XCTAssertEqual(firstAction.type, Transceiver::Action::Type::writeFrames);
+    XCTAssertEqual(firstAction.frames.size(), 1);
+
+    auto flowControl = std::vector<uint8_t> { 0x30, 0x00, 0x00, padding, padding, padding, padding, padding };
+    auto secondAction = isotp.didReceiveFrame(flowControl);
+    XCTAssertEqual(secondAction.type, Transceiver::Action::Type::writeFrames);
+    XCTAssertEqual(secondAction.frames.size(), 1);
+    auto frame = secondAction.frames[0];
+    XCTAssertEqual(frame.type(), Frame::Type::consecutive);
+    auto expected = std::vector<uint8_t> { 0x21, 0x37, 0x38, 0x55, 0x55, 0x55, 0x55, 0x55 };
+    XCTAssertEqual(frame.bytes, expected);
+}
+
+-(void)testFlowControlCustomPadding {
+    auto isotp = Transceiver(Transceiver::Behavior::strict, Transceiver::Mode::standard, 1, 0, 0, 0x55);
+    auto first = std::vector<uint8_t> { 0x10, 0x10, 0x31, 0x32, 0x33, 0x34, 0x35, 0x36 };
+    auto firstAction = isotp.didReceiveFrame(first);
+    XCTAssertEqual(firstAction.type, Transceiver::Action::Type::writeFrames);
+    XCTAssertEqual(firstAction.frames.size(), 1);
+    auto expected = std::vector<uint8_t> { 0x30, 0x01, 0x00, 0x55, 0x55, 0x55, 0x55, 0x55 };
+    XCTAssertEqual(firstAction.frames[0].bytes, expected);
+
+    // Block size is 1, so the first CF triggers another FC.
+    auto consecutive = std::vector<uint8_t> { 0x21, 0x37, 0x38, 0x39, 0x3A, 0x3B, 0x3C, 0x3D };
+    auto secondAction = isotp.didReceiveFrame(consecutive);
+    XCTAssertEqual(secondAction.type, Transceiver::Action::Type::writeFrames);
+    XCTAssertEqual(secondAction.frames.size(), 1);
+    XCTAssertEqual(secondAction.frames[0].bytes, expected);
+}
+
+-(void)testSingleUnpadded {
+    auto isotp = Transceiver(Transceiver::Behavior::strict, Transceiver::Mode::standard, 0, 0, 0, std::nullopt);
+    auto message = std::vector<uint8_t> { 0x3E, 0x00 };
+    auto action = isotp.writePDU(message);
+    XCTAssertEqual(action.type, Transceiver::Action::Type::writeFrames);
+    XCTAssertEqual(action.frames.size(), 1);
+    auto frame = action.frames[0];
+    XCTAssertEqual(frame.type(), Frame::Type::single);
+    auto expected = std::vector<uint8_t>{ 0x02, 0x3E, 0x00 };
+    XCTAssertEqual(frame.bytes, expected);
+}
+
+-(void)testFirstConsecutiveUnpadded {
+    auto isotp = Transceiver(Transceiver::Behavior::strict, Transceiver::Mode::standard, 0, 0, 0, std::nullopt);
+    auto message = std::vector<uint8_t> { 0x31, 0x32, 0x33, 0x34, 0x35, 0x36, 0x37, 0x38 };
+    auto firstAction = isotp.writePDU(message);
+    XCTAssertEqual(firstAction.type, Transceiver::Action::Type::writeFrames);
+    XCTAssertEqual(firstAction.frames.size(), 1);
+    auto expected = std::vector<uint8_t> { 0x10, 0x08, 0x31, 0x32, 0x33, 0x34, 0x35, 0x36 };
+    XCTAssertEqual(firstAction.frames[0].bytes, expected);
+
+    auto flowControl = std::vector<uint8_t> { 0x30, 0x00, 0x00, padding, padding, padding, padding, padding };
+    auto secondAction = isotp.didReceiveFrame(flowControl);
+    XCTAssertEqual(secondAction.type, Transceiver::Action::Type::writeFrames);
+    XCTAssertEqual(secondAction.frames.size(), 1);
+    auto frame = secondAction.frames[0];
+    XCTAssertEqual(frame.type(), Frame::Type::consecutive);
+    auto expected2 = std::vector<uint8_t> { 0x21, 0x37, 0x38 };
+    XCTAssertEqual(frame.bytes, expected2);
+}
+
+-(void)testFlowControlUnpadded {
+    auto isotp = Transceiver(Transceiver::Behavior::strict, Transceiver::Mode::standard, 0, 0, 0, std::nullopt);
+    auto first = std::vector<uint8_t> { 0x10, 0x08, 0x31, 0x32, 0x33, 0x34, 0x35, 0x36 };
+    auto action = isotp.didReceiveFrame(first);
+    XCTAssertEqual(action.type, Transceiver::Action::Type::writeFrames);
+    XCTAssertEqual(action.frames.size(), 1);
+    auto frame = action.frames[0];
+    XCTAssertEqual(frame.type(), Frame::Type::flowControl);
+    auto expected = std::vector<uint8_t> { 0x30, 0x00, 0x00 };
+    XCTAssertEqual(frame.bytes, expected);
+}
+
+@end